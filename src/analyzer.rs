@@ -0,0 +1,141 @@
+//! Semantic analysis over the parsed AST.
+//!
+//! Walks a `CompilationUnit`'s statements and resolves every reference,
+//! assignment target, call and binary expression against the `Index`,
+//! reporting undefined identifiers, calls to non-callable references, and
+//! operand-type mismatches. Runs after parsing and before codegen, so type
+//! errors are caught without ever reaching inkwell.
+
+use crate::ast::{CompilationUnit, Operator, Statement};
+use crate::diagnostics::Diagnostic;
+use crate::index::Index;
+
+/// Analyzes every POU in `unit`, using each POU's own name as the
+/// variable-resolution context so locals shadow globals the same way
+/// `Index::find_variable` does.
+pub fn analyze(index: &Index, unit: &CompilationUnit) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    for pou in &unit.units {
+        diagnostics.extend(analyze_statements(index, Some(pou.name.as_str()), &pou.statements));
+    }
+    diagnostics
+}
+
+/// Analyzes a standalone list of statements against `index`, resolving
+/// names under the given POU `context` (or the global scope if `None`).
+pub fn analyze_statements(index: &Index, context: Option<&str>, statements: &[Statement]) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    for statement in statements {
+        analyze_statement(index, context, statement, &mut diagnostics);
+    }
+    diagnostics
+}
+
+fn analyze_statement(index: &Index, context: Option<&str>, statement: &Statement, diagnostics: &mut Vec<Diagnostic>) {
+    match statement {
+        Statement::Reference { name, range } => {
+            if index.find_variable(context, name).is_none() {
+                diagnostics.push(Diagnostic::new(
+                    range.clone(),
+                    format!("undefined identifier '{}'", name),
+                ));
+            }
+        }
+        Statement::Assignment { left, right, .. } => {
+            analyze_statement(index, context, left, diagnostics);
+            analyze_statement(index, context, right, diagnostics);
+        }
+        Statement::CallStatement { operator, parameters, range } => {
+            if let Statement::Reference { name, .. } = operator.as_ref() {
+                if index.find_callable_instance_variable(context, name).is_none() {
+                    diagnostics.push(Diagnostic::new(
+                        range.clone(),
+                        format!("'{}' is not callable", name),
+                    ));
+                }
+            }
+            if let Some(parameters) = parameters.as_ref() {
+                analyze_statement(index, context, parameters, diagnostics);
+            }
+        }
+        Statement::UnaryExpression { value, .. } => {
+            analyze_statement(index, context, value, diagnostics);
+        }
+        Statement::BinaryExpression { operator, left, right, range } => {
+            analyze_statement(index, context, left, diagnostics);
+            analyze_statement(index, context, right, diagnostics);
+
+            if let (Some(left_type), Some(right_type)) = (
+                infer_type(index, context, left),
+                infer_type(index, context, right),
+            ) {
+                if left_type != right_type {
+                    diagnostics.push(Diagnostic::new(
+                        range.clone(),
+                        format!(
+                            "cannot apply '{:?}' to operands of type '{}' and '{}'",
+                            operator, left_type, right_type
+                        ),
+                    ));
+                }
+            }
+        }
+        Statement::RangeStatement { start, end } => {
+            analyze_statement(index, context, start, diagnostics);
+            analyze_statement(index, context, end, diagnostics);
+        }
+        Statement::ExpressionList { expressions } => {
+            for expression in expressions {
+                analyze_statement(index, context, expression, diagnostics);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Infers the datatype name of `statement`, or `None` if it can't be
+/// determined (an unresolved reference, or a non-comparison sub-expression
+/// whose own operands don't agree on a type). A comparison is `Bool` whenever
+/// both of its operands have a type, whether or not those types agree.
+fn infer_type(index: &Index, context: Option<&str>, statement: &Statement) -> Option<String> {
+    match statement {
+        Statement::Reference { name, .. } => index
+            .find_variable(context, name)
+            .map(|v| v.get_type_name().to_string()),
+        Statement::LiteralBool { .. } => Some("Bool".to_string()),
+        Statement::LiteralInteger { type_name, .. } => {
+            Some(type_name.clone().unwrap_or_else(|| "Int".to_string()))
+        }
+        Statement::LiteralReal { type_name, .. } => {
+            Some(type_name.clone().unwrap_or_else(|| "Real".to_string()))
+        }
+        Statement::UnaryExpression { value, .. } => infer_type(index, context, value),
+        Statement::BinaryExpression { operator, left, right, .. } => {
+            let left_type = infer_type(index, context, left)?;
+            let right_type = infer_type(index, context, right)?;
+            if is_comparison(*operator) {
+                Some("Bool".to_string())
+            } else if left_type == right_type {
+                Some(left_type)
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
+
+fn is_comparison(operator: Operator) -> bool {
+    matches!(
+        operator,
+        Operator::Equal
+            | Operator::NotEqual
+            | Operator::Less
+            | Operator::Greater
+            | Operator::LessOrEqual
+            | Operator::GreaterOrEqual
+    )
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,104 @@
+use super::*;
+use crate::ast::Operator;
+use crate::index::VariableType;
+
+#[test]
+fn undefined_reference_is_reported() {
+    let index = Index::new();
+    let statement = Statement::Reference {
+        name: "x".to_string(),
+        range: 0..1,
+    };
+
+    let diagnostics = analyze_statements(&index, None, &[statement]);
+
+    assert_eq!(diagnostics.len(), 1);
+    assert!(diagnostics[0].message.contains("x"));
+    assert_eq!(diagnostics[0].range, 0..1);
+}
+
+#[test]
+fn resolved_global_reference_is_not_reported() {
+    let mut index = Index::new();
+    index.register_global_variable("x".to_string(), "Int".to_string());
+    let statement = Statement::Reference {
+        name: "x".to_string(),
+        range: 0..1,
+    };
+
+    let diagnostics = analyze_statements(&index, None, &[statement]);
+
+    assert!(diagnostics.is_empty());
+}
+
+#[test]
+fn local_shadows_global_for_type_inference() {
+    let mut index = Index::new();
+    index.register_global_variable("x".to_string(), "Int".to_string());
+    index.register_local_variable(
+        "main".to_string(),
+        "x".to_string(),
+        VariableType::Local,
+        "Bool".to_string(),
+    );
+
+    let reference = Statement::Reference {
+        name: "x".to_string(),
+        range: 0..1,
+    };
+
+    assert_eq!(
+        infer_type(&index, Some("main"), &reference),
+        Some("Bool".to_string())
+    );
+    assert_eq!(
+        infer_type(&index, None, &reference),
+        Some("Int".to_string())
+    );
+}
+
+#[test]
+fn mismatched_operand_types_are_reported() {
+    let mut index = Index::new();
+    index.register_global_variable("flag".to_string(), "Bool".to_string());
+    index.register_global_variable("count".to_string(), "Int".to_string());
+
+    let statement = Statement::BinaryExpression {
+        operator: Operator::Plus,
+        left: Box::new(Statement::Reference {
+            name: "flag".to_string(),
+            range: 0..4,
+        }),
+        right: Box::new(Statement::Reference {
+            name: "count".to_string(),
+            range: 7..12,
+        }),
+        range: 0..12,
+    };
+
+    let diagnostics = analyze_statements(&index, None, &[statement]);
+
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].range, 0..12);
+}
+
+#[test]
+fn call_to_non_callable_reference_is_reported() {
+    let mut index = Index::new();
+    index.register_global_variable("x".to_string(), "Int".to_string());
+
+    let statement = Statement::CallStatement {
+        operator: Box::new(Statement::Reference {
+            name: "x".to_string(),
+            range: 0..1,
+        }),
+        parameters: Box::new(None),
+        range: 0..4,
+    };
+
+    let diagnostics = analyze_statements(&index, None, &[statement]);
+
+    assert_eq!(diagnostics.len(), 1);
+    assert!(diagnostics[0].message.contains("not callable"));
+    assert_eq!(diagnostics[0].range, 0..4);
+}
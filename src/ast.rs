@@ -0,0 +1,99 @@
+//! The abstract syntax tree produced by the parser.
+
+use std::ops::Range;
+
+/// The root of a single compiled file: the POUs (programs, function blocks,
+/// functions, ...) declared in it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompilationUnit {
+    pub units: Vec<Pou>,
+}
+
+/// A single program organization unit and the statements making up its body.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pou {
+    pub name: String,
+    pub statements: Vec<Statement>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Operator {
+    Plus,
+    Minus,
+    Multiplication,
+    Division,
+    Modulo,
+    Equal,
+    NotEqual,
+    Less,
+    Greater,
+    LessOrEqual,
+    GreaterOrEqual,
+    And,
+    Or,
+    Xor,
+    Not,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Statement {
+    RangeStatement {
+        start: Box<Statement>,
+        end: Box<Statement>,
+    },
+    ExpressionList {
+        expressions: Vec<Statement>,
+    },
+    Reference {
+        name: String,
+        range: Range<usize>,
+    },
+    Assignment {
+        left: Box<Statement>,
+        right: Box<Statement>,
+        range: Range<usize>,
+    },
+    CallStatement {
+        operator: Box<Statement>,
+        parameters: Box<Option<Statement>>,
+        range: Range<usize>,
+    },
+    UnaryExpression {
+        operator: Operator,
+        value: Box<Statement>,
+        range: Range<usize>,
+    },
+    BinaryExpression {
+        operator: Operator,
+        left: Box<Statement>,
+        right: Box<Statement>,
+        range: Range<usize>,
+    },
+    LiteralBool {
+        value: bool,
+    },
+    LiteralInteger {
+        value: i128,
+        radix: u32,
+        type_name: Option<String>,
+    },
+    LiteralReal {
+        value: f64,
+        type_name: Option<String>,
+    },
+    LiteralString {
+        value: String,
+        is_wide: bool,
+    },
+    LiteralTime {
+        milliseconds: f64,
+    },
+    LiteralDate {
+        year: Option<u16>,
+        month: Option<u8>,
+        day: Option<u8>,
+        hour: Option<u8>,
+        minute: Option<u8>,
+        second: Option<f64>,
+    },
+}
@@ -0,0 +1,91 @@
+//! An interactive REPL for experimenting with Structured Text expressions
+//! and statements without compiling a whole program.
+//!
+//! Input may span several lines: if a statement is left incomplete (an
+//! unclosed `(`, for instance) the reader keeps prompting for continuation
+//! lines and accumulating them until the parse either succeeds or fails with
+//! a genuine syntax error. Every entry is analyzed against a single `Index`
+//! kept for the whole session; no entry adds a global or POU to it, so names
+//! declared in an earlier entry are not resolvable. The input history persists
+//! across entries: enter `:history` to list every entry accumulated so far.
+
+use std::io::{self, Write};
+
+use rusty::analyzer;
+use rusty::diagnostics;
+use rusty::diagnostics::ParseError;
+use rusty::index::Index;
+use rusty::lexer::{RustyLexer, Token};
+use rusty::parser::expressions::parse_primary_expression;
+
+fn main() {
+    let mut index = Index::new();
+    let mut history: Vec<String> = Vec::new();
+    let stdin = io::stdin();
+
+    loop {
+        let mut buffer = String::new();
+        if !prompt("rusty> ") {
+            break;
+        }
+
+        loop {
+            let mut line = String::new();
+            if stdin.read_line(&mut line).unwrap_or(0) == 0 {
+                return;
+            }
+            buffer.push_str(&line);
+
+            if buffer.trim() == ":history" {
+                print_history(&history);
+                break;
+            }
+
+            let mut lexer = RustyLexer::new(&buffer);
+            match parse_primary_expression(&mut lexer) {
+                Ok(statement) => {
+                    history.push(buffer.trim_end().to_string());
+                    let diagnostics = analyzer::analyze_statements(&index, None, &[statement.clone()]);
+                    if diagnostics.is_empty() {
+                        println!("{:?}", statement);
+                    } else {
+                        println!("{}", diagnostics::render_all(&buffer, &diagnostics));
+                    }
+                    break;
+                }
+                Err(error) if is_incomplete(&error) => {
+                    if !prompt("...    ") {
+                        return;
+                    }
+                }
+                Err(error) => {
+                    history.push(buffer.trim_end().to_string());
+                    println!("{}", diagnostics::render(&buffer, &error.into_diagnostic()));
+                    break;
+                }
+            }
+        }
+    }
+}
+
+/// An input is incomplete (rather than genuinely malformed) when parsing
+/// ran off the end of the buffer while a parenthesized or other open
+/// construct was still expecting more tokens.
+fn is_incomplete(error: &ParseError) -> bool {
+    error.found == Token::End
+}
+
+/// Writes `text` as a prompt with no trailing newline. Returns `false` if
+/// stdout can no longer be written to (e.g. the REPL's output was closed).
+fn prompt(text: &str) -> bool {
+    print!("{}", text);
+    io::stdout().flush().is_ok()
+}
+
+/// Prints every entry accumulated in `history`, oldest first, in response to
+/// the `:history` command.
+fn print_history(history: &[String]) {
+    for (index, entry) in history.iter().enumerate() {
+        println!("{:>4}  {}", index + 1, entry);
+    }
+}
@@ -0,0 +1,122 @@
+use std::ops::Range;
+
+use crate::lexer::Token;
+
+/// A single problem found while parsing or analyzing a compilation unit.
+///
+/// Diagnostics carry a byte range into the original source so they can be
+/// rendered with a caret-underlined excerpt instead of a bare message.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    pub range: Range<usize>,
+    pub message: String,
+}
+
+impl Diagnostic {
+    pub fn new(range: Range<usize>, message: String) -> Diagnostic {
+        Diagnostic { range, message }
+    }
+}
+
+/// A syntax error raised while parsing.
+///
+/// Carries the offending token's span together with what was actually found
+/// and what the parser would have accepted there, so it can be rendered as
+/// an "expected X, found Y" diagnostic.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    pub range: Range<usize>,
+    pub expected: Vec<Token>,
+    pub found: Token,
+    /// A specific reason for the failure, e.g. why a literal's body is
+    /// malformed. Takes precedence over the generic "unexpected token"
+    /// wording in `message()` when present.
+    pub reason: Option<String>,
+}
+
+impl ParseError {
+    pub fn new(range: Range<usize>, expected: Vec<Token>, found: Token) -> ParseError {
+        ParseError {
+            range,
+            expected,
+            found,
+            reason: None,
+        }
+    }
+
+    /// Builds a `ParseError` for a token that was found where expected, but
+    /// whose own text doesn't parse, carrying the specific `reason` it was
+    /// rejected (e.g. "unknown time unit 'x'") instead of a generic message.
+    pub fn with_reason(range: Range<usize>, found: Token, reason: String) -> ParseError {
+        ParseError {
+            range,
+            expected: vec![],
+            found,
+            reason: Some(reason),
+        }
+    }
+
+    pub fn message(&self) -> String {
+        if let Some(reason) = &self.reason {
+            return reason.clone();
+        }
+
+        if self.expected.is_empty() {
+            format!("unexpected token '{:?}'", self.found)
+        } else {
+            format!(
+                "unexpected token '{:?}', expected one of {:?}",
+                self.found, self.expected
+            )
+        }
+    }
+
+    pub fn into_diagnostic(self) -> Diagnostic {
+        let message = self.message();
+        Diagnostic::new(self.range, message)
+    }
+}
+
+/// Renders `diagnostic` as a multi-line, caret-underlined excerpt of `source`:
+/// a line-number gutter, the offending source line, and a caret span
+/// underlining the diagnostic's range.
+pub fn render(source: &str, diagnostic: &Diagnostic) -> String {
+    let (line_number, column, line_text) = locate(source, diagnostic.range.start);
+    let underline_len = (diagnostic.range.end - diagnostic.range.start).max(1);
+    let gutter = format!("{} | ", line_number);
+
+    let mut output = String::new();
+    output.push_str(&format!("error: {}\n", diagnostic.message));
+    output.push_str(&format!("{}{}\n", gutter, line_text));
+    output.push_str(&format!(
+        "{}{}{}\n",
+        " ".repeat(gutter.len()),
+        " ".repeat(column),
+        "^".repeat(underline_len)
+    ));
+    output
+}
+
+/// Renders a whole batch of diagnostics against the same source, separated
+/// by a blank line.
+pub fn render_all(source: &str, diagnostics: &[Diagnostic]) -> String {
+    diagnostics
+        .iter()
+        .map(|d| render(source, d))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Finds the 1-based line number, 0-based column, and text of the source
+/// line containing the byte offset `start`.
+fn locate(source: &str, start: usize) -> (usize, usize, &str) {
+    let mut line_start = 0;
+    for (index, line) in source.split('\n').enumerate() {
+        let line_end = line_start + line.len();
+        if start <= line_end {
+            return (index + 1, start - line_start, line);
+        }
+        line_start = line_end + 1;
+    }
+    (1, start, source)
+}
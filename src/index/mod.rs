@@ -9,7 +9,7 @@ mod tests;
 mod visitor;
 
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct VariableIndexEntry<'ctx>{
     name                    : String,
     information             : VariableInformation,
@@ -58,7 +58,7 @@ impl <'ctx> DataTypeIndexEntry<'ctx> {
 pub enum VariableType { Local, Input, Output, InOut, Global, Return }
 
 /// information regarding a variable
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct VariableInformation {
     /// the type of variable
     variable_type   : VariableType, 
@@ -100,6 +100,32 @@ pub struct Index<'ctx> {
 
     /// all types (structs, enums, type, POUs, etc.)
     types               : HashMap<String, DataTypeIndexEntry<'ctx>>,
+
+    /// the nested block scopes (`IF`/`FOR`/...) currently open while walking
+    /// the POU being visited, innermost scope last. A name is looked up from
+    /// the innermost scope outward before falling back to the POU's flat
+    /// local map and finally the globals, so an inner declaration correctly
+    /// shadows an outer one with the same name.
+    active_scopes       : Vec<ScopeFrame<'ctx>>,
+}
+
+/// A single open block scope: the names declared directly in it, plus enough
+/// history to undo the POU-qualified flat map's view of each shadowed name
+/// once the scope is left.
+struct ScopeFrame<'ctx> {
+    locals      : HashMap<String, VariableIndexEntry<'ctx>>,
+    /// `(pou_name, variable_name, entry the flat map held before this scope
+    /// shadowed it)`, in registration order.
+    shadowed    : Vec<(String, String, Option<VariableIndexEntry<'ctx>>)>,
+}
+
+impl<'ctx> ScopeFrame<'ctx> {
+    fn new() -> ScopeFrame<'ctx> {
+        ScopeFrame {
+            locals: HashMap::new(),
+            shadowed: Vec::new(),
+        }
+    }
 }
 
 impl<'ctx> Index<'ctx> {
@@ -107,7 +133,8 @@ impl<'ctx> Index<'ctx> {
         let mut index = Index {
             global_variables : HashMap::new(),
             local_variables : HashMap::new(),
-            types : HashMap::new(),   
+            types : HashMap::new(),
+            active_scopes : Vec::new(),
         };
 
         index.types.insert("Int".to_string(), DataTypeIndexEntry{
@@ -133,12 +160,51 @@ impl<'ctx> Index<'ctx> {
     }
 
     pub fn find_variable(&self, context : Option<&str>, variable_name  : &str)  -> Option<&VariableIndexEntry<'ctx>> {
+        if let Some(entry) = self.find_in_active_scopes(variable_name) {
+            return Some(entry);
+        }
+
         match context {
             Some(context) => self.find_member(context, variable_name).or_else(||self.find_global_variable(variable_name)),
             None => self.find_global_variable(variable_name)
         }
     }
 
+    /// Searches the currently open block scopes from innermost to outermost,
+    /// without falling back to the POU's flat locals or the globals.
+    fn find_in_active_scopes(&self, variable_name: &str) -> Option<&VariableIndexEntry<'ctx>> {
+        self.active_scopes.iter().rev().find_map(|frame| frame.locals.get(variable_name))
+    }
+
+    /// Pushes a new, empty block scope (entering an `IF`/`FOR`/... body).
+    /// Names registered after this call shadow same-named declarations in
+    /// outer scopes until the matching `exit_scope`.
+    pub fn enter_scope(&mut self) {
+        self.active_scopes.push(ScopeFrame::new());
+    }
+
+    /// Pops the innermost block scope (leaving an `IF`/`FOR`/... body),
+    /// discarding its declarations and restoring the POU-qualified flat map
+    /// to whatever it held for each name this scope shadowed.
+    pub fn exit_scope(&mut self) {
+        let frame = match self.active_scopes.pop() {
+            Some(frame) => frame,
+            None => return,
+        };
+
+        for (pou_name, variable_name, previous) in frame.shadowed.into_iter().rev() {
+            let locals = self.local_variables.entry(pou_name).or_insert_with(HashMap::new);
+            match previous {
+                Some(entry) => {
+                    locals.insert(variable_name, entry);
+                }
+                None => {
+                    locals.remove(&variable_name);
+                }
+            }
+        }
+    }
+
     pub fn find_type(&self, type_name : &str) -> Option<&DataTypeIndexEntry<'ctx>> {
         self.types.get(type_name)
     }
@@ -153,13 +219,11 @@ impl<'ctx> Index<'ctx> {
         )
     }
 
-    pub fn register_local_variable(&mut self, 
-                                        pou_name: String, 
-                                        variable_name: String, 
-                                        variable_type: VariableType, 
+    pub fn register_local_variable(&mut self,
+                                        pou_name: String,
+                                        variable_name: String,
+                                        variable_type: VariableType,
                                         type_name: String) {
-        
-        let locals = self.local_variables.entry(pou_name.clone()).or_insert_with(|| HashMap::new());
 
         let entry = VariableIndexEntry{
             name : variable_name.clone(),
@@ -169,7 +233,20 @@ impl<'ctx> Index<'ctx> {
                 qualifier: Some(pou_name.clone()),
             },
             generated_reference: None,
-        };                         
+        };
+
+        // block-local declarations also go in the innermost open scope, so
+        // they shadow outer declarations during resolution; codegen keeps
+        // reading the POU-qualified flat map below, so we record what it
+        // held for this name so `exit_scope` can put it back once the block
+        // is left, instead of leaving the shadowing declaration behind.
+        if let Some(frame) = self.active_scopes.last_mut() {
+            let previous = self.local_variables.get(&pou_name).and_then(|m| m.get(&variable_name)).cloned();
+            frame.shadowed.push((pou_name.clone(), variable_name.clone(), previous));
+            frame.locals.insert(variable_name.clone(), entry.clone());
+        }
+
+        let locals = self.local_variables.entry(pou_name).or_insert_with(|| HashMap::new());
         locals.insert(variable_name, entry);
     }
 
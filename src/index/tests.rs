@@ -0,0 +1,72 @@
+use super::*;
+
+#[test]
+fn global_variable_is_found_without_context() {
+    let mut index = Index::new();
+    index.register_global_variable("x".to_string(), "Int".to_string());
+
+    let result = index.find_variable(None, "x");
+
+    assert!(result.is_some());
+    assert_eq!(result.unwrap().get_type_name(), "Int");
+}
+
+#[test]
+fn local_variable_shadows_global_of_the_same_name() {
+    let mut index = Index::new();
+    index.register_global_variable("x".to_string(), "Int".to_string());
+    index.register_local_variable("main".to_string(), "x".to_string(), VariableType::Local, "Bool".to_string());
+
+    assert_eq!(index.find_variable(Some("main"), "x").unwrap().get_type_name(), "Bool");
+    assert_eq!(index.find_variable(None, "x").unwrap().get_type_name(), "Int");
+}
+
+#[test]
+fn block_scope_shadows_the_pou_local_of_the_same_name() {
+    let mut index = Index::new();
+    index.register_local_variable("main".to_string(), "x".to_string(), VariableType::Local, "Int".to_string());
+
+    index.enter_scope();
+    index.register_local_variable("main".to_string(), "x".to_string(), VariableType::Local, "Bool".to_string());
+    assert_eq!(index.find_variable(Some("main"), "x").unwrap().get_type_name(), "Bool");
+    index.exit_scope();
+
+    assert_eq!(index.find_variable(Some("main"), "x").unwrap().get_type_name(), "Int");
+}
+
+#[test]
+fn block_local_is_still_registered_in_the_pou_qualified_map_for_codegen() {
+    let mut index = Index::new();
+
+    index.enter_scope();
+    index.register_local_variable("main".to_string(), "x".to_string(), VariableType::Local, "Int".to_string());
+    assert!(index.find_member("main", "x").is_some());
+    index.exit_scope();
+}
+
+#[test]
+fn exiting_a_scope_restores_the_pou_qualified_flat_map_for_codegen() {
+    let mut index = Index::new();
+    index.register_local_variable("main".to_string(), "x".to_string(), VariableType::Local, "Int".to_string());
+
+    index.enter_scope();
+    index.register_local_variable("main".to_string(), "x".to_string(), VariableType::Local, "Bool".to_string());
+    assert_eq!(index.find_member("main", "x").unwrap().get_type_name(), "Bool");
+    index.exit_scope();
+
+    // codegen reads the flat map directly via `find_member`; it must see the
+    // outer declaration again, not the inner block's, once the block ends.
+    assert_eq!(index.find_member("main", "x").unwrap().get_type_name(), "Int");
+}
+
+#[test]
+fn exiting_a_scope_removes_a_block_local_that_had_no_outer_declaration() {
+    let mut index = Index::new();
+
+    index.enter_scope();
+    index.register_local_variable("main".to_string(), "y".to_string(), VariableType::Local, "Int".to_string());
+    assert!(index.find_member("main", "y").is_some());
+    index.exit_scope();
+
+    assert!(index.find_member("main", "y").is_none());
+}
@@ -0,0 +1,263 @@
+//! A small hand-rolled lexer for Structured Text source.
+//!
+//! `RustyLexer` holds the current token together with its byte range in the
+//! original source (its "span"), so the parser can tag diagnostics with
+//! exactly where in the source the offending token sits.
+
+use std::ops::Range;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Token {
+    Identifier,
+    LiteralInteger,
+    LiteralTrue,
+    LiteralFalse,
+    LiteralString,
+    LiteralTime,
+    LiteralDate,
+    LiteralTimeOfDay,
+    KeywordComma,
+    KeywordDot,
+    KeywordDotDot,
+    KeywordParensOpen,
+    KeywordParensClose,
+    KeywordAssignment,
+    OperatorOr,
+    OperatorXor,
+    OperatorAnd,
+    OperatorEqual,
+    OperatorNotEqual,
+    OperatorLess,
+    OperatorGreater,
+    OperatorLessOrEqual,
+    OperatorGreaterOrEqual,
+    OperatorPlus,
+    OperatorMinus,
+    OperatorMultiplication,
+    OperatorDivision,
+    OperatorModulo,
+    OperatorNot,
+    /// end of input
+    End,
+    /// a character sequence that didn't match any known token
+    Error,
+}
+
+pub struct RustyLexer<'a> {
+    source: &'a str,
+    position: usize,
+    pub token: Token,
+    token_range: Range<usize>,
+}
+
+impl<'a> RustyLexer<'a> {
+    pub fn new(source: &'a str) -> RustyLexer<'a> {
+        let mut lexer = RustyLexer {
+            source,
+            position: 0,
+            token: Token::End,
+            token_range: 0..0,
+        };
+        lexer.advance();
+        lexer
+    }
+
+    /// Advances to the next token, updating `token` and its span.
+    pub fn advance(&mut self) {
+        self.skip_whitespace();
+
+        if self.position >= self.source.len() {
+            self.token = Token::End;
+            self.token_range = self.position..self.position;
+            return;
+        }
+
+        let start = self.position;
+        let (token, len) = scan_token(&self.source[start..]);
+        self.position += len.max(1);
+        self.token = token;
+        self.token_range = start..self.position;
+    }
+
+    /// The byte range of the current token in the original source.
+    pub fn location(&self) -> Range<usize> {
+        self.token_range.clone()
+    }
+
+    /// The source text of the current token.
+    pub fn slice(&self) -> &str {
+        &self.source[self.token_range.clone()]
+    }
+
+    /// If the raw source immediately following the current token looks like
+    /// a real literal's exponent suffix (`[eE][+-]?[0-9]+`), extends the
+    /// current token to swallow it and returns its text; otherwise leaves
+    /// the lexer untouched and returns `None`.
+    ///
+    /// This is only ever called by `parse_literal_real`, right after the
+    /// fractional digits of a real literal, never as part of normal token
+    /// scanning — an identifier like `e5` must keep lexing as `Identifier`,
+    /// not be misread as an exponent just because it matches the pattern.
+    pub fn try_take_exponent(&mut self) -> Option<String> {
+        let rest = &self.source[self.position..];
+        let len = try_scan_exponent(rest)?;
+        let text = rest[..len].to_string();
+        self.position += len;
+        self.token_range.end = self.position;
+        Some(text)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(c) = self.source[self.position..].chars().next() {
+            if c.is_whitespace() {
+                self.position += c.len_utf8();
+            } else {
+                break;
+            }
+        }
+    }
+}
+
+fn scan_token(rest: &str) -> (Token, usize) {
+    if rest.starts_with(":=") {
+        return (Token::KeywordAssignment, 2);
+    }
+    if rest.starts_with("<=") {
+        return (Token::OperatorLessOrEqual, 2);
+    }
+    if rest.starts_with(">=") {
+        return (Token::OperatorGreaterOrEqual, 2);
+    }
+    if rest.starts_with("<>") {
+        return (Token::OperatorNotEqual, 2);
+    }
+    if rest.starts_with("..") {
+        return (Token::KeywordDotDot, 2);
+    }
+
+    let first = rest.chars().next().unwrap();
+    match first {
+        '(' => (Token::KeywordParensOpen, 1),
+        ')' => (Token::KeywordParensClose, 1),
+        ',' => (Token::KeywordComma, 1),
+        '.' => (Token::KeywordDot, 1),
+        '=' => (Token::OperatorEqual, 1),
+        '<' => (Token::OperatorLess, 1),
+        '>' => (Token::OperatorGreater, 1),
+        '+' => (Token::OperatorPlus, 1),
+        '-' => (Token::OperatorMinus, 1),
+        '*' => (Token::OperatorMultiplication, 1),
+        '/' => (Token::OperatorDivision, 1),
+        '\'' => scan_string(rest, '\''),
+        '"' => scan_string(rest, '"'),
+        c if c.is_ascii_digit() => scan_number(rest),
+        c if c.is_alphabetic() || c == '_' => scan_word(rest),
+        c => (Token::Error, c.len_utf8()),
+    }
+}
+
+/// Matches a real literal's exponent suffix: `[eE][+-]?[0-9]+`. Used only by
+/// `try_take_exponent`, never by `scan_token`'s normal dispatch, since the
+/// pattern alone can't distinguish an exponent from an identifier that
+/// happens to start with `e`/`E` (e.g. `e5`).
+fn try_scan_exponent(rest: &str) -> Option<usize> {
+    let bytes = rest.as_bytes();
+    if bytes.is_empty() || (bytes[0] != b'e' && bytes[0] != b'E') {
+        return None;
+    }
+
+    let mut len = 1;
+    if bytes.get(len).map_or(false, |b| *b == b'+' || *b == b'-') {
+        len += 1;
+    }
+
+    let digits_start = len;
+    while bytes.get(len).map_or(false, u8::is_ascii_digit) {
+        len += 1;
+    }
+
+    if len == digits_start {
+        None
+    } else {
+        Some(len)
+    }
+}
+
+/// Scans a quoted string literal. Returns `Token::Error` (spanning the rest
+/// of the source) if the closing quote is never found, so an unterminated
+/// string at EOF is reported as a parse error like any other malformed
+/// token instead of being treated as a valid, silently-truncated literal.
+fn scan_string(rest: &str, quote: char) -> (Token, usize) {
+    let mut chars = rest.char_indices();
+    chars.next(); // the opening quote
+
+    let mut escaped = false;
+    for (index, c) in chars {
+        if escaped {
+            escaped = false;
+        } else if c == '\\' {
+            escaped = true;
+        } else if c == quote {
+            return (Token::LiteralString, index + c.len_utf8());
+        }
+    }
+    (Token::Error, rest.len())
+}
+
+fn scan_number(rest: &str) -> (Token, usize) {
+    let mut len = digit_run_len(rest);
+    if rest[len..].starts_with('#') {
+        len += 1 + literal_tail_len(&rest[len + 1..]);
+    }
+    (Token::LiteralInteger, len)
+}
+
+fn scan_word(rest: &str) -> (Token, usize) {
+    let len = word_len(rest);
+    let word = &rest[..len];
+
+    if rest[len..].starts_with('#') {
+        let token = match word.to_ascii_uppercase().as_str() {
+            "T" | "TIME" => Token::LiteralTime,
+            "DATE" | "D" => Token::LiteralDate,
+            "TOD" | "TIME_OF_DAY" => Token::LiteralTimeOfDay,
+            _ => Token::LiteralInteger,
+        };
+        let tail_len = 1 + literal_tail_len(&rest[len + 1..]);
+        return (token, len + tail_len);
+    }
+
+    let token = match word.to_ascii_uppercase().as_str() {
+        "TRUE" => Token::LiteralTrue,
+        "FALSE" => Token::LiteralFalse,
+        "OR" => Token::OperatorOr,
+        "XOR" => Token::OperatorXor,
+        "AND" => Token::OperatorAnd,
+        "MOD" => Token::OperatorModulo,
+        "NOT" => Token::OperatorNot,
+        _ => Token::Identifier,
+    };
+    (token, len)
+}
+
+fn digit_run_len(text: &str) -> usize {
+    text.chars().take_while(|c| c.is_ascii_digit()).map(char::len_utf8).sum()
+}
+
+fn word_len(text: &str) -> usize {
+    text.chars()
+        .take_while(|c| c.is_alphanumeric() || *c == '_')
+        .map(char::len_utf8)
+        .sum()
+}
+
+/// Consumes the tail of a based/typed numeric literal or a duration/date/
+/// time-of-day literal's body: digits, letters, `_`, a nested `#` (for
+/// `WORD#16#FF`), `-` (a leading sign or a date separator; accepted at any
+/// position) and `:` (time-of-day separators).
+fn literal_tail_len(text: &str) -> usize {
+    text.chars()
+        .take_while(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '#' | '-' | ':'))
+        .map(char::len_utf8)
+        .sum()
+}
@@ -0,0 +1,82 @@
+//! Parsing helpers for IEC 61131-3 numeric literals: plain decimal integers,
+//! based integers (`16#FF`, `8#17`, `2#1010_1010`) and typed literals
+//! (`INT#-5`, `WORD#16#FF`, `REAL#1.5`).
+
+use std::ops::Range;
+
+use crate::diagnostics::ParseError;
+use crate::lexer::Token;
+
+/// Splits an optional `TYPENAME#` prefix off the front of a numeric literal's
+/// text, e.g. `"INT#-5"` -> `(Some("INT"), "-5")`, `"16#FF"` -> `(None, "16#FF")`.
+fn split_type_prefix(text: &str) -> (Option<String>, &str) {
+    if let Some(hash_index) = text.find('#') {
+        let prefix = &text[..hash_index];
+        if prefix.chars().next().map_or(false, char::is_alphabetic) {
+            return (Some(prefix.to_string()), &text[hash_index + 1..]);
+        }
+    }
+    (None, text)
+}
+
+/// Splits a `2#`/`8#`/`16#` radix prefix off the front of `text`, returning
+/// the detected radix (10 if none) and the remaining digits.
+fn split_radix_prefix(text: &str) -> (u32, &str) {
+    for (radix, prefix) in [(16u32, "16#"), (8, "8#"), (2, "2#")] {
+        if let Some(rest) = text.strip_prefix(prefix) {
+            return (radix, rest);
+        }
+    }
+    (10, text)
+}
+
+/// Parses `digits` (optionally `-`-prefixed, optionally containing `_`
+/// separators) as an integer in the given `radix`, validating every digit.
+fn parse_digits(digits: &str, radix: u32, range: Range<usize>) -> Result<i128, ParseError> {
+    let (negative, digits) = match digits.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, digits),
+    };
+
+    let cleaned: String = digits.chars().filter(|c| *c != '_').collect();
+    if cleaned.is_empty() {
+        return Err(ParseError::new(range, vec![], Token::LiteralInteger));
+    }
+
+    let mut value: i128 = 0;
+    for c in cleaned.chars() {
+        let digit = c
+            .to_digit(radix)
+            .ok_or_else(|| ParseError::new(range.clone(), vec![], Token::LiteralInteger))?;
+        value = value * radix as i128 + digit as i128;
+    }
+
+    Ok(if negative { -value } else { value })
+}
+
+/// The parsed parts of an integer literal: its value, the radix it was
+/// written in, and an optional explicit type name (`INT#`, `WORD#`, ...).
+pub struct ParsedInteger {
+    pub value: i128,
+    pub radix: u32,
+    pub type_name: Option<String>,
+}
+
+/// Parses the raw text of an integer-literal token (everything before an
+/// optional `.` fractional part), e.g. `"16#FF"`, `"WORD#16#FF"`, `"INT#-5"`.
+pub fn parse_integer_literal(text: &str, range: Range<usize>) -> Result<ParsedInteger, ParseError> {
+    let (type_name, rest) = split_type_prefix(text);
+    let (radix, digits) = split_radix_prefix(rest);
+    let value = parse_digits(digits, radix, range)?;
+    Ok(ParsedInteger {
+        value,
+        radix,
+        type_name,
+    })
+}
+
+/// Strips an optional `TYPENAME#` prefix off the integer part of a real
+/// literal, e.g. `"REAL#1"` -> `(Some("REAL"), "1")`.
+pub fn split_real_type_prefix(text: &str) -> (Option<String>, &str) {
+    split_type_prefix(text)
+}
@@ -1,4 +1,5 @@
 use crate::ast::*;
+use crate::diagnostics::ParseError;
 use crate::expect;
 use crate::lexer::Token::*;
 
@@ -6,14 +7,23 @@ use super::allow;
 use super::RustyLexer;
 use super::{slice_and_advance, unexpected_token};
 
+mod literals;
+use literals::{parse_integer_literal, split_real_type_prefix};
+
+mod temporal;
+use temporal::{parse_date, parse_duration_millis, parse_time_of_day};
+
+mod text;
+use text::{is_wide_string, unescape_string};
+
 #[cfg(test)]
 mod tests;
 
-pub fn parse_primary_expression(lexer: &mut RustyLexer) -> Result<Statement, String> {
+pub fn parse_primary_expression(lexer: &mut RustyLexer) -> Result<Statement, ParseError> {
     parse_expression_list(lexer)
 }
 
-fn parse_expression_list(lexer: &mut RustyLexer) -> Result<Statement, String> {
+fn parse_expression_list(lexer: &mut RustyLexer) -> Result<Statement, ParseError> {
     let left = parse_range_statement(lexer);
     if lexer.token == KeywordComma {
         let mut expressions = Vec::new();
@@ -28,7 +38,7 @@ fn parse_expression_list(lexer: &mut RustyLexer) -> Result<Statement, String> {
     Ok(left?)
 }
 
-fn parse_range_statement(lexer: &mut RustyLexer) -> Result<Statement, String> {
+fn parse_range_statement(lexer: &mut RustyLexer) -> Result<Statement, ParseError> {
     let start = parse_or_expression(lexer)?;
 
     if lexer.token == KeywordDotDot {
@@ -43,7 +53,8 @@ fn parse_range_statement(lexer: &mut RustyLexer) -> Result<Statement, String> {
 }
 
 // OR
-fn parse_or_expression(lexer: &mut RustyLexer) -> Result<Statement, String> {
+fn parse_or_expression(lexer: &mut RustyLexer) -> Result<Statement, ParseError> {
+    let start = lexer.location().start;
     let left = parse_xor_expression(lexer)?;
 
     let operator = match lexer.token {
@@ -58,11 +69,13 @@ fn parse_or_expression(lexer: &mut RustyLexer) -> Result<Statement, String> {
         operator,
         left: Box::new(left),
         right: Box::new(right),
+        range: start..lexer.location().start,
     })
 }
 
 // XOR
-fn parse_xor_expression(lexer: &mut RustyLexer) -> Result<Statement, String> {
+fn parse_xor_expression(lexer: &mut RustyLexer) -> Result<Statement, ParseError> {
+    let start = lexer.location().start;
     let left = parse_and_expression(lexer)?;
 
     let operator = match lexer.token {
@@ -77,11 +90,13 @@ fn parse_xor_expression(lexer: &mut RustyLexer) -> Result<Statement, String> {
         operator,
         left: Box::new(left),
         right: Box::new(right),
+        range: start..lexer.location().start,
     })
 }
 
 // AND
-fn parse_and_expression(lexer: &mut RustyLexer) -> Result<Statement, String> {
+fn parse_and_expression(lexer: &mut RustyLexer) -> Result<Statement, ParseError> {
+    let start = lexer.location().start;
     let left = parse_equality_expression(lexer)?;
 
     let operator = match lexer.token {
@@ -96,11 +111,13 @@ fn parse_and_expression(lexer: &mut RustyLexer) -> Result<Statement, String> {
         operator,
         left: Box::new(left),
         right: Box::new(right),
+        range: start..lexer.location().start,
     })
 }
 
 //EQUALITY  =, <>
-fn parse_equality_expression(lexer: &mut RustyLexer) -> Result<Statement, String> {
+fn parse_equality_expression(lexer: &mut RustyLexer) -> Result<Statement, ParseError> {
+    let start = lexer.location().start;
     let left = parse_compare_expression(lexer)?;
     let operator = match lexer.token {
         OperatorEqual => Operator::Equal,
@@ -113,11 +130,13 @@ fn parse_equality_expression(lexer: &mut RustyLexer) -> Result<Statement, String
         operator,
         left: Box::new(left),
         right: Box::new(right),
+        range: start..lexer.location().start,
     })
 }
 
 //COMPARE <, >, <=, >=
-fn parse_compare_expression(lexer: &mut RustyLexer) -> Result<Statement, String> {
+fn parse_compare_expression(lexer: &mut RustyLexer) -> Result<Statement, ParseError> {
+    let start = lexer.location().start;
     let left = parse_additive_expression(lexer)?;
     let operator = match lexer.token {
         OperatorLess => Operator::Less,
@@ -132,11 +151,13 @@ fn parse_compare_expression(lexer: &mut RustyLexer) -> Result<Statement, String>
         operator,
         left: Box::new(left),
         right: Box::new(right),
+        range: start..lexer.location().start,
     })
 }
 
 // Addition +, -
-fn parse_additive_expression(lexer: &mut RustyLexer) -> Result<Statement, String> {
+fn parse_additive_expression(lexer: &mut RustyLexer) -> Result<Statement, ParseError> {
+    let start = lexer.location().start;
     let left = parse_multiplication_expression(lexer)?;
     let operator = match lexer.token {
         OperatorPlus => Operator::Plus,
@@ -149,11 +170,13 @@ fn parse_additive_expression(lexer: &mut RustyLexer) -> Result<Statement, String
         operator,
         left: Box::new(left),
         right: Box::new(right),
+        range: start..lexer.location().start,
     })
 }
 
 // Multiplication *, /, MOD
-fn parse_multiplication_expression(lexer: &mut RustyLexer) -> Result<Statement, String> {
+fn parse_multiplication_expression(lexer: &mut RustyLexer) -> Result<Statement, ParseError> {
+    let start = lexer.location().start;
     let left = parse_unary_expression(lexer)?;
     let operator = match lexer.token {
         OperatorMultiplication => Operator::Multiplication,
@@ -167,10 +190,12 @@ fn parse_multiplication_expression(lexer: &mut RustyLexer) -> Result<Statement,
         operator,
         left: Box::new(left),
         right: Box::new(right),
+        range: start..lexer.location().start,
     })
 }
 // UNARY -x, NOT x
-fn parse_unary_expression(lexer: &mut RustyLexer) -> Result<Statement, String> {
+fn parse_unary_expression(lexer: &mut RustyLexer) -> Result<Statement, ParseError> {
+    let start = lexer.location().start;
     let operator = match lexer.token {
         OperatorNot => Some(Operator::Not),
         OperatorMinus => Some(Operator::Minus),
@@ -180,8 +205,9 @@ fn parse_unary_expression(lexer: &mut RustyLexer) -> Result<Statement, String> {
     if let Some(operator) = operator {
         lexer.advance();
         Ok(Statement::UnaryExpression {
-            operator: operator,
+            operator,
             value: Box::new(parse_parenthesized_expression(lexer)?),
+            range: start..lexer.location().start,
         })
     } else {
         parse_parenthesized_expression(lexer)
@@ -189,7 +215,7 @@ fn parse_unary_expression(lexer: &mut RustyLexer) -> Result<Statement, String> {
 }
 
 // PARENTHESIZED (...)
-fn parse_parenthesized_expression(lexer: &mut RustyLexer) -> Result<Statement, String> {
+fn parse_parenthesized_expression(lexer: &mut RustyLexer) -> Result<Statement, ParseError> {
     match lexer.token {
         KeywordParensOpen => {
             lexer.advance();
@@ -203,12 +229,17 @@ fn parse_parenthesized_expression(lexer: &mut RustyLexer) -> Result<Statement, S
 }
 
 // Literals, Identifiers, etc.
-fn parse_leaf_expression(lexer: &mut RustyLexer) -> Result<Statement, String> {
+fn parse_leaf_expression(lexer: &mut RustyLexer) -> Result<Statement, ParseError> {
+    let start = lexer.location().start;
     let current = match lexer.token {
         Identifier => parse_reference(lexer),
         LiteralInteger => parse_literal_number(lexer),
         LiteralTrue => parse_bool_literal(lexer, true),
         LiteralFalse => parse_bool_literal(lexer, false),
+        LiteralString => parse_string_literal(lexer),
+        LiteralTime => parse_time_literal(lexer),
+        LiteralDate => parse_date_literal(lexer),
+        LiteralTimeOfDay => parse_time_of_day_literal(lexer),
         _ => Err(unexpected_token(lexer)),
     };
 
@@ -217,19 +248,22 @@ fn parse_leaf_expression(lexer: &mut RustyLexer) -> Result<Statement, String> {
         return Ok(Statement::Assignment {
             left: Box::new(current?),
             right: Box::new(parse_range_statement(lexer)?),
+            range: start..lexer.location().start,
         });
     };
     current
 }
 
-fn parse_bool_literal(lexer: &mut RustyLexer, value: bool) -> Result<Statement, String> {
+fn parse_bool_literal(lexer: &mut RustyLexer, value: bool) -> Result<Statement, ParseError> {
     lexer.advance();
     Ok(Statement::LiteralBool { value })
 }
 
-pub fn parse_reference(lexer: &mut RustyLexer) -> Result<Statement, String> {
+pub fn parse_reference(lexer: &mut RustyLexer) -> Result<Statement, ParseError> {
+    let start = lexer.location().start;
     let reference = Statement::Reference {
         name: slice_and_advance(lexer).to_string(),
+        range: start..lexer.location().start,
     };
 
     if allow(KeywordParensOpen, lexer) {
@@ -244,30 +278,91 @@ pub fn parse_reference(lexer: &mut RustyLexer) -> Result<Statement, String> {
         Ok(Statement::CallStatement {
             operator: Box::new(reference),
             parameters: Box::new(statement_list),
+            range: start..lexer.location().start,
         })
     } else {
         Ok(reference)
     }
 }
 
-fn parse_literal_number(lexer: &mut RustyLexer) -> Result<Statement, String> {
+fn parse_literal_number(lexer: &mut RustyLexer) -> Result<Statement, ParseError> {
+    let location = lexer.location();
     let result = slice_and_advance(lexer);
     if allow(KeywordDot, lexer) {
         return parse_literal_real(lexer, result);
     }
 
-    Ok(Statement::LiteralInteger { value: result })
+    let parsed = parse_integer_literal(&result, location)?;
+    Ok(Statement::LiteralInteger {
+        value: parsed.value,
+        radix: parsed.radix,
+        type_name: parsed.type_name,
+    })
 }
 
-fn parse_literal_real(lexer: &mut RustyLexer, integer: String) -> Result<Statement, String> {
+fn parse_literal_real(lexer: &mut RustyLexer, integer: String) -> Result<Statement, ParseError> {
+    let location = lexer.location();
     expect!(LiteralInteger, lexer);
-    let fractional = slice_and_advance(lexer);
-    let exponent = if lexer.token == LiteralExponent {
-        slice_and_advance(lexer)
-    } else {
-        "".to_string()
-    };
-    let result = format!("{}.{}{}", integer, fractional, exponent);
+    let fractional = lexer.slice().to_string();
+    let exponent = lexer.try_take_exponent().unwrap_or_default();
+    lexer.advance();
+
+    let (type_name, integer_digits) = split_real_type_prefix(&integer);
+    let text = format!("{}.{}{}", integer_digits, fractional, exponent);
+    let value: f64 = text
+        .parse()
+        .map_err(|_| ParseError::new(location, vec![], LiteralInteger))?;
 
-    Ok(Statement::LiteralReal { value: result })
+    Ok(Statement::LiteralReal { value, type_name })
+}
+
+fn parse_string_literal(lexer: &mut RustyLexer) -> Result<Statement, ParseError> {
+    let raw = slice_and_advance(lexer);
+    Ok(Statement::LiteralString {
+        value: unescape_string(&raw),
+        is_wide: is_wide_string(&raw),
+    })
+}
+
+/// Strips a literal's `TYPE#`/`T#` prefix, e.g. `"TIME#1h30m"` -> `"1h30m"`.
+fn strip_hash_prefix(raw: &str) -> &str {
+    raw.splitn(2, '#').nth(1).unwrap_or(raw)
+}
+
+fn parse_time_literal(lexer: &mut RustyLexer) -> Result<Statement, ParseError> {
+    let location = lexer.location();
+    let raw = slice_and_advance(lexer);
+    let milliseconds = parse_duration_millis(strip_hash_prefix(&raw))
+        .map_err(|reason| ParseError::with_reason(location, LiteralTime, reason))?;
+    Ok(Statement::LiteralTime { milliseconds })
+}
+
+fn parse_date_literal(lexer: &mut RustyLexer) -> Result<Statement, ParseError> {
+    let location = lexer.location();
+    let raw = slice_and_advance(lexer);
+    let parsed = parse_date(strip_hash_prefix(&raw))
+        .map_err(|reason| ParseError::with_reason(location, LiteralDate, reason))?;
+    Ok(Statement::LiteralDate {
+        year: Some(parsed.year),
+        month: Some(parsed.month),
+        day: Some(parsed.day),
+        hour: None,
+        minute: None,
+        second: None,
+    })
+}
+
+fn parse_time_of_day_literal(lexer: &mut RustyLexer) -> Result<Statement, ParseError> {
+    let location = lexer.location();
+    let raw = slice_and_advance(lexer);
+    let parsed = parse_time_of_day(strip_hash_prefix(&raw))
+        .map_err(|reason| ParseError::with_reason(location, LiteralTimeOfDay, reason))?;
+    Ok(Statement::LiteralDate {
+        year: None,
+        month: None,
+        day: None,
+        hour: Some(parsed.hour),
+        minute: Some(parsed.minute),
+        second: Some(parsed.second),
+    })
 }
\ No newline at end of file
@@ -0,0 +1,109 @@
+//! Parsing helpers for Structured Text duration (`T#5s`), date (`DATE#...`)
+//! and time-of-day (`TOD#...`) literals. The caller is expected to have
+//! already stripped the leading `T#`/`TIME#`/`DATE#`/`TOD#` type prefix.
+
+/// Parses a sum of `<number><unit>` components (`ms`/`s`/`m`/`h`/`d`) into a
+/// normalized duration in milliseconds, e.g. `"1h30m"` -> `5_400_000.0`.
+pub fn parse_duration_millis(text: &str) -> Result<f64, String> {
+    let mut total_ms = 0.0;
+    let mut any_component = false;
+    let mut rest = text;
+
+    while !rest.is_empty() {
+        let number_end = rest
+            .find(|c: char| !c.is_ascii_digit() && c != '.')
+            .unwrap_or(rest.len());
+        if number_end == 0 {
+            return Err(format!("expected a number at '{}'", rest));
+        }
+        let number_text = &rest[..number_end];
+        let after_number = &rest[number_end..];
+
+        let unit_end = after_number
+            .find(|c: char| c.is_ascii_digit())
+            .unwrap_or(after_number.len());
+        let unit = &after_number[..unit_end];
+
+        let factor = unit_factor_millis(unit).ok_or_else(|| format!("unknown time unit '{}'", unit))?;
+        let value: f64 = number_text
+            .parse()
+            .map_err(|_| format!("invalid number '{}'", number_text))?;
+
+        total_ms += value * factor;
+        any_component = true;
+        rest = &after_number[unit_end..];
+    }
+
+    if !any_component {
+        return Err("empty duration literal".to_string());
+    }
+    Ok(total_ms)
+}
+
+fn unit_factor_millis(unit: &str) -> Option<f64> {
+    match unit {
+        "d" => Some(86_400_000.0),
+        "h" => Some(3_600_000.0),
+        "m" => Some(60_000.0),
+        "s" => Some(1_000.0),
+        "ms" => Some(1.0),
+        _ => None,
+    }
+}
+
+/// A parsed `DATE#yyyy-mm-dd` literal.
+pub struct ParsedDate {
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+}
+
+/// Parses a `yyyy-mm-dd` date body.
+pub fn parse_date(text: &str) -> Result<ParsedDate, String> {
+    let mut parts = text.splitn(3, '-');
+    let year = parts
+        .next()
+        .ok_or_else(|| "missing year".to_string())?
+        .parse()
+        .map_err(|_| format!("invalid year in date literal '{}'", text))?;
+    let month = parts
+        .next()
+        .ok_or_else(|| "missing month".to_string())?
+        .parse()
+        .map_err(|_| format!("invalid month in date literal '{}'", text))?;
+    let day = parts
+        .next()
+        .ok_or_else(|| "missing day".to_string())?
+        .parse()
+        .map_err(|_| format!("invalid day in date literal '{}'", text))?;
+    Ok(ParsedDate { year, month, day })
+}
+
+/// A parsed `TOD#hh:mm:ss` literal.
+pub struct ParsedTimeOfDay {
+    pub hour: u8,
+    pub minute: u8,
+    pub second: f64,
+}
+
+/// Parses an `hh:mm[:ss]` time-of-day body.
+pub fn parse_time_of_day(text: &str) -> Result<ParsedTimeOfDay, String> {
+    let mut parts = text.splitn(3, ':');
+    let hour = parts
+        .next()
+        .ok_or_else(|| "missing hour".to_string())?
+        .parse()
+        .map_err(|_| format!("invalid hour in time-of-day literal '{}'", text))?;
+    let minute = parts
+        .next()
+        .ok_or_else(|| "missing minute".to_string())?
+        .parse()
+        .map_err(|_| format!("invalid minute in time-of-day literal '{}'", text))?;
+    let second = match parts.next() {
+        Some(text) => text
+            .parse()
+            .map_err(|_| format!("invalid second in time-of-day literal '{}'", text))?,
+        None => 0.0,
+    };
+    Ok(ParsedTimeOfDay { hour, minute, second })
+}
@@ -0,0 +1,41 @@
+//! Parsing helpers for Structured Text string literals (`'abc'`, `"abc"`).
+
+/// Strips the surrounding quote characters from a raw string-literal token
+/// and resolves backslash escapes (`\n`, `\t`, `\\`, `\'`, `\"`).
+///
+/// Only ever called with a properly closed token (the lexer reports an
+/// unterminated string as `Token::Error`, not `Token::LiteralString`), but
+/// guards the bounds anyway rather than trust that invariant with a panic.
+pub fn unescape_string(raw: &str) -> String {
+    let inner = raw.get(1..raw.len().saturating_sub(1)).unwrap_or("");
+    let mut result = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('n') => result.push('\n'),
+            Some('t') => result.push('\t'),
+            Some('\\') => result.push('\\'),
+            Some('\'') => result.push('\''),
+            Some('"') => result.push('"'),
+            Some(other) => {
+                result.push('\\');
+                result.push(other);
+            }
+            None => result.push('\\'),
+        }
+    }
+
+    result
+}
+
+/// A double-quoted literal (`"abc"`) is a wide (`WSTRING`) string; a
+/// single-quoted one (`'abc'`) is a regular `STRING`.
+pub fn is_wide_string(raw: &str) -> bool {
+    raw.starts_with('"')
+}
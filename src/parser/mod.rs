@@ -0,0 +1,46 @@
+//! Turns a token stream from the `RustyLexer` into an AST.
+
+pub mod expressions;
+
+use crate::diagnostics::ParseError;
+use crate::lexer::{RustyLexer, Token};
+
+/// Builds a `ParseError` from the lexer's current position and token. Used
+/// wherever a production falls through every pattern it knows how to parse.
+pub fn unexpected_token(lexer: &RustyLexer) -> ParseError {
+    ParseError::new(lexer.location(), vec![], lexer.token)
+}
+
+/// Returns the source text of the lexer's current token and advances past it.
+pub fn slice_and_advance(lexer: &mut RustyLexer) -> String {
+    let text = lexer.slice().to_string();
+    lexer.advance();
+    text
+}
+
+/// If the current token is `token`, advances past it and returns `true`;
+/// otherwise leaves the lexer where it is and returns `false`.
+pub fn allow(token: Token, lexer: &mut RustyLexer) -> bool {
+    if lexer.token == token {
+        lexer.advance();
+        true
+    } else {
+        false
+    }
+}
+
+/// Fails the enclosing function with a `ParseError` built from the lexer's
+/// current position and token (see `unexpected_token`), recording `$token`
+/// as the single expected token, unless the current token already is it.
+#[macro_export]
+macro_rules! expect {
+    ($token:expr, $lexer:expr) => {
+        if $lexer.token != $token {
+            return Err($crate::diagnostics::ParseError::new(
+                $lexer.location(),
+                vec![$token],
+                $lexer.token,
+            ));
+        }
+    };
+}